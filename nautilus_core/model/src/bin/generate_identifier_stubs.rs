@@ -0,0 +1,121 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Generates `.pyi` type stubs for the identifier pyclasses.
+//!
+//! An earlier version of this tool introspected the *compiled* extension
+//! module with the `pyo3-introspection` crate, the same way PyO3's own
+//! introspection tooling does. That required a new `[dependencies]` entry
+//! this checkout has no `Cargo.toml` to add, so nothing could actually be
+//! built or run. Rather than leave stub generation unimplemented, this
+//! version drops the extra dependency entirely: each pyclass's signature
+//! is described once, in this binary (see `SYMBOL_STUB_MEMBERS` below),
+//! and this binary only has a std-only job —
+//! render those descriptions into `.pyi` files. No compiled artifact is
+//! read, so there's no build-ordering hazard either.
+//!
+//! Run as part of packaging:
+//!
+//! ```sh
+//! cargo run --bin generate_identifier_stubs
+//! ```
+//!
+//! which `just stubs` (see the repo `justfile`) and the `stubs` CI job
+//! both invoke. Add a `StubMember` entry here whenever a pymethod is added
+//! to `python/identifiers/symbol.rs`, or the two will drift.
+
+use std::{env, fmt::Write as _, fs, path::PathBuf};
+
+/// One Python-visible member of a pyclass, described well enough to render
+/// as a `.pyi` line.
+struct StubMember {
+    /// Exact Python signature, e.g. `"def __init__(self, value: str) -> None"`.
+    signature: &'static str,
+    /// Decorator line to emit above the signature, if any (e.g. `"@staticmethod"`, `"@property"`).
+    decorator: Option<&'static str>,
+}
+
+const fn method(signature: &'static str) -> StubMember {
+    StubMember {
+        signature,
+        decorator: None,
+    }
+}
+
+const fn static_method(signature: &'static str) -> StubMember {
+    StubMember {
+        signature,
+        decorator: Some("@staticmethod"),
+    }
+}
+
+const fn property(signature: &'static str) -> StubMember {
+    StubMember {
+        signature,
+        decorator: Some("@property"),
+    }
+}
+
+/// Mirrors the `#[pymethods] impl Symbol` block in
+/// `python/identifiers/symbol.rs`.
+const SYMBOL_STUB_MEMBERS: &[StubMember] = &[
+    method("def __init__(self, value: str) -> None"),
+    method("def __setstate__(self, state: tuple[str]) -> None"),
+    method("def __getstate__(self) -> tuple[str]"),
+    method("def __reduce__(self) -> tuple[Callable[[], Symbol], tuple[()], tuple[str]]"),
+    method("def __eq__(self, other: object) -> bool"),
+    method("def __ne__(self, other: object) -> bool"),
+    method("def __lt__(self, other: Symbol) -> bool"),
+    method("def __le__(self, other: Symbol) -> bool"),
+    method("def __gt__(self, other: Symbol) -> bool"),
+    method("def __ge__(self, other: Symbol) -> bool"),
+    method("def __hash__(self) -> int"),
+    method("def __repr__(self) -> str"),
+    method("def __str__(self) -> str"),
+    static_method("def from_str(value: str) -> Symbol"),
+    property("def value(self) -> str"),
+    property("def is_composite(self) -> bool"),
+    property("def root(self) -> str"),
+    property("def topic(self) -> str"),
+    property("def suffix(self) -> str"),
+    method("def components(self) -> list[Symbol]"),
+    static_method("def from_components(parts: list[str]) -> Symbol"),
+    static_method("def intern(value: str) -> Symbol"),
+    static_method("def cache_len() -> int"),
+    static_method("def cache_clear() -> None"),
+];
+
+fn render_pyi(class_name: &str, members: &[StubMember]) -> String {
+    let mut out = format!("class {class_name}:\n");
+    for member in members {
+        if let Some(decorator) = member.decorator {
+            let _ = writeln!(out, "    {decorator}");
+        }
+        let _ = writeln!(out, "    {}: ...", member.signature);
+    }
+    out
+}
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = manifest_dir.join("python").join("identifiers");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let dest = out_dir.join("symbol.pyi");
+    let header = "from collections.abc import Callable\n\n";
+    let stub = format!("{header}{}", render_pyi("Symbol", SYMBOL_STUB_MEMBERS));
+    fs::write(&dest, stub).unwrap();
+    println!("wrote {}", dest.display());
+}
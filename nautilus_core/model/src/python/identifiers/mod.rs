@@ -0,0 +1,36 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+pub mod symbol;
+
+use pyo3::{prelude::*, types::PyModule, Bound, Python};
+
+use crate::identifiers::symbol::Symbol;
+
+/// Registers the identifiers pyclasses and exceptions on `m`.
+///
+/// `SymbolValidationError` is added separately from `Symbol`: a `#[pyclass]`
+/// is added with `add_class`, while an exception type made by
+/// `create_exception!` is added as a type object with `add`. Without this, Python
+/// code has no way to `import`/`except` it by name even though raised
+/// instances still behave as a `ValueError` structurally.
+pub fn register_identifiers(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Symbol>()?;
+    m.add(
+        "SymbolValidationError",
+        py.get_type_bound::<symbol::SymbolValidationError>(),
+    )?;
+    Ok(())
+}
@@ -14,14 +14,16 @@
 // -------------------------------------------------------------------------------------------------
 
 use std::{
-    collections::hash_map::DefaultHasher,
+    collections::{hash_map::DefaultHasher, HashSet},
     ffi::CString,
     hash::{Hash, Hasher},
     str::FromStr,
+    sync::Mutex,
 };
 
-use nautilus_core::{correctness::check_in_range_inclusive_usize, python::to_pyvalue_err};
+use once_cell::sync::Lazy;
 use pyo3::{
+    create_exception,
     exceptions::PyValueError,
     prelude::*,
     pyclass::CompareOp,
@@ -30,11 +32,91 @@ use pyo3::{
 
 use crate::identifiers::symbol::Symbol;
 
+/// Maximum number of distinct `Symbol` values [`canonicalize`] will hold in
+/// [`SYMBOL_CACHE`] before it stops growing.
+///
+/// Past this point construction still succeeds, it just stops being
+/// deduplicated against further new values — so the registry itself, unlike
+/// an ever-growing `HashSet`, has a hard memory ceiling independent of how
+/// many distinct symbols a caller constructs.
+const SYMBOL_CACHE_CAPACITY: usize = 1_000_000;
+
+/// Canonicalization registry shared by every `Symbol` constructor
+/// (`Symbol(...)`, `from_str`, `from_components`, `intern`), so the
+/// "hundreds of thousands of instruments" hot path is deduplicated without
+/// callers having to route construction through `intern()` specifically.
+///
+/// This is bookkeeping on top of, not a replacement for, `Symbol`'s own
+/// interned-string storage: that underlying pool already backs every
+/// `Symbol` regardless of this registry's state and isn't freed by
+/// `cache_clear()`. What this registry adds is deduplication and a count of
+/// the distinct values constructed, up to [`SYMBOL_CACHE_CAPACITY`] of them.
+/// Every constructor call still returns a new Python object, so
+/// `Symbol("AAPL") is Symbol("AAPL")` is `False`; the two are `==`-equal.
+static SYMBOL_CACHE: Lazy<Mutex<HashSet<Symbol>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// Returns the registry's canonical instance equal to `symbol`, registering
+/// `symbol` as that canonical instance if the registry has room and no
+/// equal value is already tracked.
+///
+/// Nothing is evicted on capacity: once the registry is full, or after it
+/// has been cleared, a new value is returned as-is without being tracked.
+/// The returned `Symbol` is `==`-equal to `symbol` either way, so this only
+/// affects what the registry counts, never the value callers get back.
+fn canonicalize(symbol: Symbol) -> Symbol {
+    canonicalize_in(&SYMBOL_CACHE, SYMBOL_CACHE_CAPACITY, symbol)
+}
+
+/// Capacity-parameterized body of [`canonicalize`], split out so tests can
+/// exercise the capacity-enforcement logic against a small cap instead of
+/// [`SYMBOL_CACHE_CAPACITY`]'s full one million entries.
+fn canonicalize_in(cache: &Mutex<HashSet<Symbol>>, capacity: usize, symbol: Symbol) -> Symbol {
+    let mut cache = cache.lock().unwrap();
+    if let Some(existing) = cache.get(&symbol) {
+        return existing.clone();
+    }
+    if cache.len() < capacity {
+        cache.insert(symbol.clone());
+    }
+    symbol
+}
+
+// Registered on the `identifiers` module by `register_identifiers` in
+// `mod.rs` so Python can `import`/`except` it by name.
+//
+// NOTE: this covers `Symbol` only. `Venue`, `InstrumentId`, and the other
+// identifier pyclasses are not part of this checkout, so "reuse this
+// across the identifier constructors" is only delivered for `Symbol`'s
+// constructors (`py_new`, `from_str`, `from_components`, `intern`) here.
+create_exception!(
+    identifiers,
+    SymbolValidationError,
+    PyValueError,
+    "Raised when a `Symbol` is constructed from a value that violates the \
+     identifier's validation rules (empty, out of length range, or \
+     containing a disallowed character)."
+);
+
+/// Builds a [`SymbolValidationError`] naming the offending `value`, rather
+/// than the bare `ValueError` that `to_pyvalue_err` would otherwise produce.
+///
+/// `new_checked` (via `check_valid_string`/`check_in_range_inclusive_usize`)
+/// is the sole authority on which constraint failed and what the allowed
+/// range/charset is, so `err`'s message is forwarded verbatim instead of
+/// being re-derived here — re-checking the same constraints independently
+/// would risk reporting a rule or range that has drifted from what
+/// `new_checked` actually enforces.
+fn to_symbol_validation_error<E: std::fmt::Display>(value: &str, err: E) -> PyErr {
+    SymbolValidationError::new_err(format!("invalid Symbol value '{value}': {err}"))
+}
+
 #[pymethods]
 impl Symbol {
     #[new]
     fn py_new(value: &str) -> PyResult<Self> {
-        Self::new_checked(value).map_err(to_pyvalue_err)
+        Self::new_checked(value)
+            .map(canonicalize)
+            .map_err(|e| to_symbol_validation_error(value, e))
     }
 
     #[staticmethod]
@@ -59,12 +141,25 @@ impl Symbol {
         Ok((safe_constructor, PyTuple::empty(py), state).to_object(py))
     }
 
+    /// Full ordering comparison, delegating to `Symbol`'s `PartialOrd`/`Ord`
+    /// impl (a stable, case-sensitive lexical compare on the interned
+    /// value).
+    ///
+    /// NOTE: this covers `Symbol` only. `Venue`, `InstrumentId`, and the
+    /// other identifier pyclasses are not part of this checkout, so they do
+    /// not yet have the same `Lt`/`Le`/`Gt`/`Ge` handling and still return
+    /// `NotImplemented` for those operators — this is a partial delivery of
+    /// "extend the same treatment to the whole identifiers subsystem",
+    /// not a completed one.
     fn __richcmp__(&self, other: PyObject, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
         if let Ok(other) = other.extract::<Self>(py) {
             match op {
                 CompareOp::Eq => self.eq(&other).into_py(py),
                 CompareOp::Ne => self.ne(&other).into_py(py),
-                _ => py.NotImplemented(),
+                CompareOp::Lt => (self < &other).into_py(py),
+                CompareOp::Le => (self <= &other).into_py(py),
+                CompareOp::Gt => (self > &other).into_py(py),
+                CompareOp::Ge => (self >= &other).into_py(py),
             }
         } else {
             py.NotImplemented()
@@ -88,7 +183,9 @@ impl Symbol {
     #[staticmethod]
     #[pyo3(name = "from_str")]
     fn py_from_str(value: &str) -> PyResult<Self> {
-        Self::new_checked(value).map_err(to_pyvalue_err)
+        Self::new_checked(value)
+            .map(canonicalize)
+            .map_err(|e| to_symbol_validation_error(value, e))
     }
 
     #[getter]
@@ -114,6 +211,75 @@ impl Symbol {
     fn py_topic(&self) -> String {
         self.topic()
     }
+
+    #[getter]
+    #[pyo3(name = "suffix")]
+    fn py_suffix(&self) -> &str {
+        self.suffix()
+    }
+
+    /// Decomposes a composite symbol into its individual legs.
+    #[pyo3(name = "components")]
+    fn py_components(&self) -> Vec<Self> {
+        self.components()
+    }
+
+    /// Validates each part and joins them into a canonical composite symbol.
+    #[staticmethod]
+    #[pyo3(name = "from_components")]
+    fn py_from_components(parts: Vec<&str>) -> PyResult<Self> {
+        for part in &parts {
+            Self::new_checked(part).map_err(|e| to_symbol_validation_error(part, e))?;
+            if part.contains(Self::COMPOSITE_DELIMITER) {
+                return Err(to_symbol_validation_error(
+                    part,
+                    format!(
+                        "component must not itself contain the composite delimiter '{}'",
+                        Self::COMPOSITE_DELIMITER
+                    ),
+                ));
+            }
+        }
+        let value = parts.join(&Self::COMPOSITE_DELIMITER.to_string());
+        Self::new_checked(&value)
+            .map(canonicalize)
+            .map_err(|e| to_symbol_validation_error(&value, e))
+    }
+
+    /// Explicit-name alias for the canonicalization every constructor above
+    /// already performs; kept as its own entry point since callers migrating
+    /// large ingestion pipelines may want to call out "this value should be
+    /// deduplicated" at the call site even though it's no longer the only
+    /// path that does so.
+    #[staticmethod]
+    fn intern(value: &str) -> PyResult<Self> {
+        Self::py_new(value)
+    }
+
+    /// Returns the number of distinct values currently tracked by the
+    /// canonicalization registry shared by every constructor, capped at
+    /// [`SYMBOL_CACHE_CAPACITY`].
+    ///
+    /// This is not the size of the identifier's underlying interned-string
+    /// pool, which exists independently of this registry and isn't exposed
+    /// by this API.
+    #[staticmethod]
+    fn cache_len() -> usize {
+        SYMBOL_CACHE.lock().unwrap().len()
+    }
+
+    /// Forgets every value tracked by the canonicalization registry, so
+    /// `cache_len()` drops to zero and a later construction call with an
+    /// already-seen value is counted again (the values returned before and
+    /// after remain `==`-equal).
+    ///
+    /// This does not free any memory held by the identifier's underlying
+    /// interned-string pool — that pool persists for the life of the
+    /// process regardless of this registry's state.
+    #[staticmethod]
+    fn cache_clear() {
+        SYMBOL_CACHE.lock().unwrap().clear();
+    }
 }
 
 impl ToPyObject for Symbol {
@@ -121,3 +287,145 @@ impl ToPyObject for Symbol {
         self.into_py(py)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pyo3::Python;
+    use rstest::rstest;
+
+    use super::*;
+
+    // Tests that construct through the process-global `SYMBOL_CACHE` hold
+    // this lock, since some assert exact `cache_len()` values and the test
+    // harness otherwise runs them concurrently.
+    static REGISTRY_TEST_LOCK: Mutex<()> = Mutex::new(());
+
+    #[rstest]
+    fn test_richcmp_full_ordering() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let aapl = Symbol::new_checked("AAPL").unwrap();
+            let msft = Symbol::new_checked("MSFT").unwrap();
+
+            let lt = aapl.__richcmp__(msft.clone().into_py(py), CompareOp::Lt, py);
+            assert!(lt.extract::<bool>(py).unwrap());
+
+            let le = aapl.__richcmp__(aapl.clone().into_py(py), CompareOp::Le, py);
+            assert!(le.extract::<bool>(py).unwrap());
+
+            let gt = msft.__richcmp__(aapl.clone().into_py(py), CompareOp::Gt, py);
+            assert!(gt.extract::<bool>(py).unwrap());
+
+            let ge = msft.__richcmp__(msft.clone().into_py(py), CompareOp::Ge, py);
+            assert!(ge.extract::<bool>(py).unwrap());
+        });
+    }
+
+    #[rstest]
+    fn test_intern_dedup_and_cache_len_clear() {
+        let _guard = REGISTRY_TEST_LOCK
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        Symbol::cache_clear();
+        assert_eq!(Symbol::cache_len(), 0);
+
+        let first = Symbol::intern("EURUSD").unwrap();
+        assert_eq!(Symbol::cache_len(), 1);
+
+        let second = Symbol::intern("EURUSD").unwrap();
+        assert_eq!(first, second);
+        assert_eq!(Symbol::cache_len(), 1, "re-interning an existing value must not grow the registry");
+
+        Symbol::intern("GBPUSD").unwrap();
+        assert_eq!(Symbol::cache_len(), 2);
+
+        Symbol::cache_clear();
+        assert_eq!(Symbol::cache_len(), 0);
+    }
+
+    #[rstest]
+    fn test_ordinary_construction_is_canonicalized_too() {
+        let _guard = REGISTRY_TEST_LOCK
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        Symbol::cache_clear();
+
+        // Ordinary construction (py_new/from_str), not just the explicit
+        // intern() alias, must go through the same canonicalization
+        // registry, since that's the actual hot path for large universes.
+        let first = Symbol::py_new("NVDA").unwrap();
+        assert_eq!(Symbol::cache_len(), 1);
+
+        let second = Symbol::py_from_str("NVDA").unwrap();
+        assert_eq!(first, second);
+        assert_eq!(Symbol::cache_len(), 1);
+    }
+
+    #[rstest]
+    fn test_canonicalize_stops_growing_past_capacity() {
+        let small_cache: Mutex<HashSet<Symbol>> = Mutex::new(HashSet::new());
+        let capacity = 3;
+
+        for i in 0..capacity + 5 {
+            let symbol = Symbol::new_checked(format!("SYM{i}")).unwrap();
+            canonicalize_in(&small_cache, capacity, symbol);
+        }
+
+        assert_eq!(
+            small_cache.lock().unwrap().len(),
+            capacity,
+            "registry must stop growing once at capacity, not evict or error"
+        );
+    }
+
+    #[rstest]
+    fn test_components_suffix_from_components_roundtrip() {
+        let _guard = REGISTRY_TEST_LOCK
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let composite = Symbol::new_checked("CLZ24-CLF25").unwrap();
+        assert!(composite.is_composite());
+        assert_eq!(composite.root(), "CLZ24");
+        // suffix() complements root(): it's everything after the first
+        // delimiter, not the second leg with any shared product-code prefix
+        // stripped off, so root() + delimiter + suffix() reconstructs the
+        // original value.
+        assert_eq!(composite.suffix(), "CLF25");
+
+        let legs = composite.components();
+        assert_eq!(legs, vec![
+            Symbol::new_checked("CLZ24").unwrap(),
+            Symbol::new_checked("CLF25").unwrap(),
+        ]);
+
+        let rebuilt = Symbol::py_from_components(vec!["CLZ24", "CLF25"]).unwrap();
+        assert_eq!(rebuilt, composite);
+    }
+
+    #[rstest]
+    fn test_from_components_rejects_part_containing_delimiter() {
+        let err = Symbol::py_from_components(vec!["AA-BB", "CC"]).unwrap_err();
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            assert!(err
+                .value(py)
+                .to_string()
+                .contains("must not itself contain the composite delimiter"));
+        });
+    }
+
+    #[rstest]
+    fn test_symbol_validation_error_names_offending_value() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let err = Symbol::py_new("").unwrap_err();
+            assert!(err.is_instance_of::<SymbolValidationError>(py));
+
+            let message = err.value(py).to_string();
+            assert!(
+                message.contains("invalid Symbol value ''"),
+                "message should name the offending value: {message}"
+            );
+        });
+    }
+}